@@ -23,6 +23,233 @@ impl gdbstub::arch::BreakpointKind for BpfBreakpointKind {
     }
 }
 
+/// Target triple reported to LLDB via `qHostInfo` for BPF targets (both
+/// [`Bpf`] and [`Bpf64`]), used when `target_description_xml` is `None`.
+pub const BPF_LLDB_TRIPLE: &str = "bpf-unknown-none";
+
+/// Reference `qHostInfo` implementation for BPF.
+///
+/// `ptrsize` should be `core::mem::size_of::<<Bpf as Arch>::Usize>()` (or
+/// `Bpf64`'s), and endianness is always little, matching
+/// [`BpfRegs::gdb_serialize`](reg::BpfRegs::gdb_serialize)'s byte order.
+/// A BPF `Target`'s `LldbHostInfoOverride::host_info` implementation can
+/// simply delegate to this function.
+pub fn lldb_host_info(
+    ptrsize: usize,
+) -> gdbstub::target::ext::lldb_host_info_override::HostInfo<'static> {
+    use gdbstub::target::ext::lldb_host_info_override::{Endian, HostInfo};
+
+    HostInfo {
+        triple: BPF_LLDB_TRIPLE,
+        ptrsize,
+        endian: Endian::Little,
+        ostype: None,
+        vendor: None,
+    }
+}
+
+/// Target description XML register descriptors for BPF's GPRs (R0-R9),
+/// shared between [`Bpf`] (32-bit `sp`/`pc`) and [`Bpf64`] (64-bit
+/// `sp`/`pc`). `regnum` matches the order
+/// [`BpfRegs::gdb_serialize`](reg::BpfRegs::gdb_serialize) writes
+/// registers in, and `sp_pc_bitsize` is the `sp`/`pc` width in bits.
+macro_rules! bpf_register_descriptors {
+    ($sp_pc_bitsize:expr) => {
+        [
+            gdbstub::arch::target_description_xml::RegisterDescriptor {
+                name: "r0",
+                bitsize: 64,
+                regnum: 0,
+                reg_type: "int64",
+                group: "org.gnu.gdb.bpf.core",
+            },
+            gdbstub::arch::target_description_xml::RegisterDescriptor {
+                name: "r1",
+                bitsize: 64,
+                regnum: 1,
+                reg_type: "int64",
+                group: "org.gnu.gdb.bpf.core",
+            },
+            gdbstub::arch::target_description_xml::RegisterDescriptor {
+                name: "r2",
+                bitsize: 64,
+                regnum: 2,
+                reg_type: "int64",
+                group: "org.gnu.gdb.bpf.core",
+            },
+            gdbstub::arch::target_description_xml::RegisterDescriptor {
+                name: "r3",
+                bitsize: 64,
+                regnum: 3,
+                reg_type: "int64",
+                group: "org.gnu.gdb.bpf.core",
+            },
+            gdbstub::arch::target_description_xml::RegisterDescriptor {
+                name: "r4",
+                bitsize: 64,
+                regnum: 4,
+                reg_type: "int64",
+                group: "org.gnu.gdb.bpf.core",
+            },
+            gdbstub::arch::target_description_xml::RegisterDescriptor {
+                name: "r5",
+                bitsize: 64,
+                regnum: 5,
+                reg_type: "int64",
+                group: "org.gnu.gdb.bpf.core",
+            },
+            gdbstub::arch::target_description_xml::RegisterDescriptor {
+                name: "r6",
+                bitsize: 64,
+                regnum: 6,
+                reg_type: "int64",
+                group: "org.gnu.gdb.bpf.core",
+            },
+            gdbstub::arch::target_description_xml::RegisterDescriptor {
+                name: "r7",
+                bitsize: 64,
+                regnum: 7,
+                reg_type: "int64",
+                group: "org.gnu.gdb.bpf.core",
+            },
+            gdbstub::arch::target_description_xml::RegisterDescriptor {
+                name: "r8",
+                bitsize: 64,
+                regnum: 8,
+                reg_type: "int64",
+                group: "org.gnu.gdb.bpf.core",
+            },
+            gdbstub::arch::target_description_xml::RegisterDescriptor {
+                name: "r9",
+                bitsize: 64,
+                regnum: 9,
+                reg_type: "int64",
+                group: "org.gnu.gdb.bpf.core",
+            },
+            gdbstub::arch::target_description_xml::RegisterDescriptor {
+                name: "sp",
+                bitsize: $sp_pc_bitsize,
+                regnum: 10,
+                reg_type: "data_ptr",
+                group: "org.gnu.gdb.bpf.core",
+            },
+            gdbstub::arch::target_description_xml::RegisterDescriptor {
+                name: "pc",
+                bitsize: $sp_pc_bitsize,
+                regnum: 11,
+                reg_type: "code_ptr",
+                group: "org.gnu.gdb.bpf.core",
+            },
+        ]
+    };
+}
+
+/// Register descriptors for [`Bpf`]'s (32-bit) target description XML.
+pub const BPF_REGISTERS: [gdbstub::arch::target_description_xml::RegisterDescriptor; 12] =
+    bpf_register_descriptors!(32);
+/// Register descriptors for [`Bpf64`]'s target description XML.
+pub const BPF64_REGISTERS: [gdbstub::arch::target_description_xml::RegisterDescriptor; 12] =
+    bpf_register_descriptors!(64);
+
+/// A build-once cache for a generated target description XML string.
+///
+/// `target_description_xml()` is called once per GDB/LLDB session, so a
+/// long-lived process that's attached to repeatedly (e.g. an emulator)
+/// must not allocate a fresh string every time -- that leaks memory
+/// without bound. This builds the string at most once per arch and
+/// reuses the same `'static` string thereafter.
+///
+/// With the (default) `std` feature, this is just a thin wrapper around
+/// [`std::sync::OnceLock`]. Without it, there's no safe `no_std`-friendly
+/// one-time-init primitive in `core`/`alloc` to reach for, so the cache
+/// falls back to a small lock-free CAS loop over a leaked allocation.
+#[cfg(feature = "std")]
+struct XmlCache(std::sync::OnceLock<alloc::string::String>);
+
+#[cfg(feature = "std")]
+impl XmlCache {
+    const fn new() -> Self {
+        XmlCache(std::sync::OnceLock::new())
+    }
+
+    /// Returns the cached string, building it via `build` the first time
+    /// this is called.
+    fn get_or_init(&'static self, build: impl FnOnce() -> alloc::string::String) -> &'static str {
+        self.0.get_or_init(build).as_str()
+    }
+}
+
+#[cfg(not(feature = "std"))]
+struct XmlCache {
+    state: core::sync::atomic::AtomicU8,
+    ptr: core::sync::atomic::AtomicUsize,
+    len: core::sync::atomic::AtomicUsize,
+}
+
+#[cfg(not(feature = "std"))]
+impl XmlCache {
+    const UNINIT: u8 = 0;
+    const BUILDING: u8 = 1;
+    const READY: u8 = 2;
+
+    const fn new() -> Self {
+        XmlCache {
+            state: core::sync::atomic::AtomicU8::new(Self::UNINIT),
+            ptr: core::sync::atomic::AtomicUsize::new(0),
+            len: core::sync::atomic::AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the cached string, building (and leaking) it via `build`
+    /// the first time this is called.
+    fn get_or_init(&self, build: impl FnOnce() -> alloc::string::String) -> &'static str {
+        use core::sync::atomic::Ordering;
+
+        loop {
+            match self.state.compare_exchange(
+                Self::UNINIT,
+                Self::BUILDING,
+                Ordering::Acquire,
+                Ordering::Acquire,
+            ) {
+                Ok(_) => {
+                    let xml: &'static str = alloc::boxed::Box::leak(build().into_boxed_str());
+                    self.ptr.store(xml.as_ptr() as usize, Ordering::Relaxed);
+                    self.len.store(xml.len(), Ordering::Relaxed);
+                    self.state.store(Self::READY, Ordering::Release);
+                    break;
+                }
+                Err(Self::READY) => break,
+                Err(_) => core::hint::spin_loop(),
+            }
+        }
+
+        let ptr = self.ptr.load(Ordering::Acquire) as *const u8;
+        let len = self.len.load(Ordering::Acquire);
+        // SAFETY: `ptr`/`len` are only ever written once, to a leaked
+        // `'static` UTF-8 string, before `state` is published as `READY`
+        // with `Release` ordering -- every reader that observes `READY`
+        // (via the `Acquire` loads above) observes that write too.
+        unsafe { core::str::from_utf8_unchecked(core::slice::from_raw_parts(ptr, len)) }
+    }
+}
+
+#[cfg(feature = "alloc")]
+static BPF_XML: XmlCache = XmlCache::new();
+#[cfg(feature = "alloc")]
+static BPF64_XML: XmlCache = XmlCache::new();
+
+#[cfg(feature = "alloc")]
+fn bpf_target_description_xml(
+    cache: &'static XmlCache,
+    registers: &'static [gdbstub::arch::target_description_xml::RegisterDescriptor],
+) -> &'static str {
+    use gdbstub::arch::target_description_xml::TargetDescriptionXmlBuilder;
+
+    cache
+        .get_or_init(|| TargetDescriptionXmlBuilder::new("org.gnu.gdb.bpf.core", registers).build())
+}
+
 /// Implements `Arch` for 32-bit BPF.
 pub enum Bpf {}
 
@@ -37,7 +264,14 @@ impl Arch for Bpf {
     type BreakpointKind = BpfBreakpointKind;
 
     fn target_description_xml() -> Option<&'static str> {
-        None
+        #[cfg(feature = "alloc")]
+        {
+            Some(bpf_target_description_xml(&BPF_XML, &BPF_REGISTERS))
+        }
+        #[cfg(not(feature = "alloc"))]
+        {
+            None
+        }
     }
 
     #[inline(always)]
@@ -54,7 +288,14 @@ impl Arch for Bpf64 {
     type BreakpointKind = BpfBreakpointKind;
 
     fn target_description_xml() -> Option<&'static str> {
-        None
+        #[cfg(feature = "alloc")]
+        {
+            Some(bpf_target_description_xml(&BPF64_XML, &BPF64_REGISTERS))
+        }
+        #[cfg(not(feature = "alloc"))]
+        {
+            None
+        }
     }
 
     #[inline(always)]
@@ -62,3 +303,91 @@ impl Arch for Bpf64 {
         SingleStepGdbBehavior::Required
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use gdbstub::arch::Registers;
+
+    /// Registers must be numbered in the exact order
+    /// `BpfRegs::gdb_serialize` writes them in, and their bitsizes must
+    /// sum to the total number of bytes it serializes -- otherwise the
+    /// target description XML and the `g`/`G` packet layout would drift
+    /// apart.
+    fn assert_layout_matches_gdb_serialize(
+        registers: &[gdbstub::arch::target_description_xml::RegisterDescriptor],
+        serialized_len: usize,
+    ) {
+        for (i, reg) in registers.iter().enumerate() {
+            assert_eq!(reg.regnum, i, "{} has an out-of-order regnum", reg.name);
+            assert_eq!(
+                reg.bitsize % 8,
+                0,
+                "{} has a non-byte-aligned bitsize",
+                reg.name
+            );
+        }
+        let total_bits: usize = registers.iter().map(|reg| reg.bitsize).sum();
+        assert_eq!(total_bits / 8, serialized_len);
+    }
+
+    fn gdb_serialized_len<R: Registers>(regs: &R) -> usize {
+        let mut len = 0;
+        regs.gdb_serialize(|b| {
+            if b.is_some() {
+                len += 1;
+            }
+        });
+        len
+    }
+
+    #[test]
+    fn bpf_register_descriptors_match_gdb_serialize_layout() {
+        let regs = reg::BpfRegs::<u32>::default();
+        assert_layout_matches_gdb_serialize(&BPF_REGISTERS, gdb_serialized_len(&regs));
+    }
+
+    #[test]
+    fn bpf64_register_descriptors_match_gdb_serialize_layout() {
+        let regs = reg::BpfRegs::<u64>::default();
+        assert_layout_matches_gdb_serialize(&BPF64_REGISTERS, gdb_serialized_len(&regs));
+    }
+
+    #[test]
+    fn lldb_host_info_reports_bpf_triple_and_ptrsize() {
+        let info = lldb_host_info(core::mem::size_of::<u64>());
+        assert_eq!(info.triple, BPF_LLDB_TRIPLE);
+        assert_eq!(info.ptrsize, 8);
+    }
+
+    #[test]
+    fn target_description_xml_is_cached_across_calls() {
+        // Repeated calls (e.g. repeated GDB/LLDB sessions against a
+        // long-lived process) must reuse the same leaked allocation
+        // rather than leaking a fresh one every time.
+        let first = <Bpf64 as Arch>::target_description_xml().unwrap();
+        let second = <Bpf64 as Arch>::target_description_xml().unwrap();
+        assert_eq!(first.as_ptr(), second.as_ptr());
+    }
+
+    #[test]
+    fn target_description_xml_is_cached_across_concurrent_callers() {
+        // Several GDB/LLDB sessions can attach to the same long-lived
+        // process at once, racing to build the cache. Every caller must
+        // observe the same, fully-built string -- not a torn write, and
+        // not one leaked allocation per racing thread.
+        let handles: alloc::vec::Vec<_> = (0..8)
+            .map(|_| std::thread::spawn(<Bpf as Arch>::target_description_xml))
+            .collect();
+
+        let results: alloc::vec::Vec<&'static str> = handles
+            .into_iter()
+            .map(|h| h.join().unwrap().unwrap())
+            .collect();
+
+        for xml in &results {
+            assert_eq!(xml.as_ptr(), results[0].as_ptr());
+            assert!(xml.contains("org.gnu.gdb.bpf.core"));
+        }
+    }
+}
@@ -40,3 +40,113 @@ impl RegId for BpfRegId<u64> {
         from_raw_id::<u64>(id)
     }
 }
+
+impl<U> BpfRegId<U> {
+    /// Returns the byte offset of register `id` within the `g`/`G` packet
+    /// register block, along with its width in bytes, matching the order
+    /// [`BpfRegs::gdb_serialize`](super::BpfRegs::gdb_serialize) writes
+    /// registers in.
+    ///
+    /// `ptrsize` is the width (in bytes) of the `sp`/`pc` registers, i.e.
+    /// `core::mem::size_of::<U>()`.
+    ///
+    /// Useful when implementing LLDB's `qRegisterInfo` for a BPF target,
+    /// since LLDB's `offset`/`bitsize` fields must exactly match the `g`
+    /// packet layout.
+    pub fn gdb_serialize_layout(id: usize, ptrsize: usize) -> Option<(usize, usize)> {
+        let layout = match id {
+            // GPRs (R0-R9) are 8 bytes wide, packed back-to-back
+            0..=9 => (id * 8, 8),
+            // SP (R10), immediately after the GPRs
+            10 => (8 * 10, ptrsize),
+            // PC (R11), immediately after SP
+            11 => (8 * 10 + ptrsize, ptrsize),
+            _ => return None,
+        };
+        Some(layout)
+    }
+}
+
+/// Reference `qRegisterInfo` implementation for BPF.
+///
+/// Builds the [`RegisterInfo`](gdbstub::target::ext::lldb_register_info_override::RegisterInfo)
+/// LLDB expects for register `id`, using [`BpfRegId::gdb_serialize_layout`]
+/// to keep `offset`/`bitsize` in sync with the `g` packet. A BPF
+/// `Target`'s `LldbRegisterInfoOverride::register_info` implementation
+/// can simply delegate to this function:
+///
+/// ```rust,ignore
+/// impl LldbRegisterInfoOverride for MyBpfTarget {
+///     fn register_info(&self, reg_id: usize) -> Option<RegisterInfo<'_>> {
+///         gdbstub_arch::bpf::reg::id::lldb_register_info::<u64>(reg_id, 8)
+///     }
+/// }
+/// ```
+pub fn lldb_register_info<U>(
+    id: usize,
+    ptrsize: usize,
+) -> Option<gdbstub::target::ext::lldb_register_info_override::RegisterInfo<'static>> {
+    use gdbstub::target::ext::lldb_register_info_override::{
+        RegisterEncoding, RegisterFormat, RegisterInfo,
+    };
+
+    let (offset, width_bytes) = BpfRegId::<U>::gdb_serialize_layout(id, ptrsize)?;
+    let name: &'static str = match id {
+        0 => "r0",
+        1 => "r1",
+        2 => "r2",
+        3 => "r3",
+        4 => "r4",
+        5 => "r5",
+        6 => "r6",
+        7 => "r7",
+        8 => "r8",
+        9 => "r9",
+        10 => "sp",
+        11 => "pc",
+        _ => return None,
+    };
+
+    Some(RegisterInfo {
+        name,
+        alt_name: None,
+        bitsize: width_bytes * 8,
+        offset,
+        encoding: RegisterEncoding::Uint,
+        format: RegisterFormat::Hex,
+        set: "General Purpose Registers",
+        gdb_regnum: Some(id),
+        dwarf_regnum: Some(id),
+        generic: match id {
+            10 => Some("sp"),
+            11 => Some("pc"),
+            _ => None,
+        },
+        container_regs: None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gdb_serialize_layout_covers_all_registers() {
+        assert_eq!(BpfRegId::<u32>::gdb_serialize_layout(0, 4), Some((0, 8)));
+        assert_eq!(BpfRegId::<u32>::gdb_serialize_layout(9, 4), Some((72, 8)));
+        assert_eq!(BpfRegId::<u32>::gdb_serialize_layout(10, 4), Some((80, 4)));
+        assert_eq!(BpfRegId::<u32>::gdb_serialize_layout(11, 4), Some((84, 4)));
+        assert_eq!(BpfRegId::<u32>::gdb_serialize_layout(12, 4), None);
+    }
+
+    #[test]
+    fn lldb_register_info_matches_layout() {
+        let pc = lldb_register_info::<u32>(11, 4).unwrap();
+        assert_eq!(pc.name, "pc");
+        assert_eq!(pc.offset, 84);
+        assert_eq!(pc.bitsize, 32);
+        assert_eq!(pc.generic, Some("pc"));
+
+        assert!(lldb_register_info::<u32>(12, 4).is_none());
+    }
+}
@@ -0,0 +1,82 @@
+//! A small builder for constructing valid target description XML
+//! (a `<target>` document containing a single `<feature>`) from a
+//! declarative list of register descriptors.
+//!
+//! Hand-writing `target_description_xml()` is tedious and easy to get
+//! subtly wrong (stray registers, mismatched `regnum`s, ...). This
+//! builder lets arch implementors describe their registers as plain
+//! data instead, and keeps the XML and the data in sync.
+
+/// Describes a single register for the purposes of generating target
+/// description XML.
+#[derive(Debug, Clone, Copy)]
+pub struct RegisterDescriptor {
+    /// Register name, as it appears in the `<reg>` tag's `name` attribute.
+    pub name: &'static str,
+    /// Register width, in bits.
+    pub bitsize: usize,
+    /// Register number. Must match the order
+    /// [`Registers::gdb_serialize`](crate::arch::Registers::gdb_serialize)
+    /// writes registers in.
+    pub regnum: usize,
+    /// GDB register type, e.g. `"int64"`, `"code_ptr"`, `"data_ptr"`.
+    pub reg_type: &'static str,
+    /// The feature group this register belongs to, e.g.
+    /// `"org.gnu.gdb.bpf.core"`.
+    pub group: &'static str,
+}
+
+/// Builds a `<target>` XML document containing a single `<feature>` from
+/// a list of [`RegisterDescriptor`]s.
+///
+/// ```
+/// use gdbstub::arch::target_description_xml::{RegisterDescriptor, TargetDescriptionXmlBuilder};
+///
+/// const REGS: &[RegisterDescriptor] = &[RegisterDescriptor {
+///     name: "r0",
+///     bitsize: 64,
+///     regnum: 0,
+///     reg_type: "int64",
+///     group: "org.gnu.gdb.bpf.core",
+/// }];
+///
+/// let xml = TargetDescriptionXmlBuilder::new("org.gnu.gdb.bpf.core", REGS).build();
+/// assert!(xml.contains(r#"<reg name="r0" bitsize="64" regnum="0" type="int64" group="org.gnu.gdb.bpf.core"/>"#));
+/// ```
+pub struct TargetDescriptionXmlBuilder {
+    feature_name: &'static str,
+    registers: &'static [RegisterDescriptor],
+}
+
+impl TargetDescriptionXmlBuilder {
+    /// Create a new builder for a single-feature target description,
+    /// covering the given registers.
+    pub const fn new(feature_name: &'static str, registers: &'static [RegisterDescriptor]) -> Self {
+        TargetDescriptionXmlBuilder {
+            feature_name,
+            registers,
+        }
+    }
+
+    /// Render the target description as an XML string.
+    #[cfg(feature = "alloc")]
+    pub fn build(&self) -> alloc::string::String {
+        use alloc::format;
+        use alloc::string::String;
+
+        let mut xml = String::new();
+        xml.push_str("<?xml version=\"1.0\"?>\n");
+        xml.push_str("<!DOCTYPE target SYSTEM \"gdb-target.dtd\">\n");
+        xml.push_str("<target>\n");
+        xml.push_str(&format!("  <feature name=\"{}\">\n", self.feature_name));
+        for reg in self.registers {
+            xml.push_str(&format!(
+                "    <reg name=\"{}\" bitsize=\"{}\" regnum=\"{}\" type=\"{}\" group=\"{}\"/>\n",
+                reg.name, reg.bitsize, reg.regnum, reg.reg_type, reg.group
+            ));
+        }
+        xml.push_str("  </feature>\n");
+        xml.push_str("</target>\n");
+        xml
+    }
+}
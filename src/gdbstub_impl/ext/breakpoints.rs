@@ -0,0 +1,125 @@
+//! Dispatch logic enforcing the hardware breakpoint/watchpoint capacity
+//! declared via
+//! [`HwBreakpoint::max_hw_breakpoints`](crate::target::ext::breakpoints::HwBreakpoint::max_hw_breakpoints)
+//! and
+//! [`HwWatchpoint::max_hw_watchpoints`](crate::target::ext::breakpoints::HwWatchpoint::max_hw_watchpoints).
+
+/// Tracks how many hardware breakpoints (or watchpoints) are currently
+/// installed, and checks new installs against a target-declared cap
+/// before forwarding the request to the target.
+///
+/// `GdbStub` keeps one instance of this tracker for HW breakpoints and a
+/// separate instance for HW watchpoints, since targets may declare
+/// different capacities for each. On every `Z1` (or `Z2`/`Z3`/`Z4`)
+/// packet, dispatch:
+///
+/// 1. calls `tracker.has_room(target.max_hw_breakpoints())` (or
+///    `max_hw_watchpoints()`), bailing out with the returned `E` error
+///    if there's no room, without ever calling
+///    `target.add_hw_breakpoint(..)`;
+/// 2. calls `target.add_hw_breakpoint(..)`;
+/// 3. calls `tracker.record_install()` only once that call actually
+///    returns `Ok(true)`.
+///
+/// On `z1`/`z2`/`z3`/`z4`, it calls `tracker.remove()` after a
+/// successful `target.remove_hw_breakpoint(..)`.
+#[derive(Debug, Default)]
+pub(crate) struct HwBreakpointTracker {
+    installed: usize,
+}
+
+impl HwBreakpointTracker {
+    pub(crate) fn new() -> Self {
+        Self::default()
+    }
+
+    /// Checks a new hardware breakpoint/watchpoint install against the
+    /// target's declared capacity, without reserving a slot.
+    ///
+    /// Returns `Ok(())` if there's room. Returns `Err` with the reply
+    /// `GdbStub` should send GDB instead of ever calling
+    /// `add_hw_breakpoint`/`add_hw_watchpoint` -- a clean "no more
+    /// hardware breakpoints available" error, rather than forwarding a
+    /// request the target would have to fail (or silently clobber an
+    /// existing one) on its own.
+    ///
+    /// A slot isn't counted as used until [`record_install`](Self::record_install)
+    /// is called -- if `add_hw_breakpoint`/`add_hw_watchpoint` goes on
+    /// to fail for a reason unrelated to capacity (bad address,
+    /// alignment, a target-internal failure, ...), the target never
+    /// installed anything, so the tracker shouldn't count it either.
+    pub(crate) fn has_room(&self, max: Option<usize>) -> Result<(), &'static str> {
+        if let Some(max) = max {
+            if self.installed >= max {
+                return Err("E01");
+            }
+        }
+        Ok(())
+    }
+
+    /// Records that a hardware breakpoint/watchpoint was actually
+    /// installed, after a call to `add_hw_breakpoint`/`add_hw_watchpoint`
+    /// returned `Ok(true)`.
+    pub(crate) fn record_install(&mut self) {
+        self.installed += 1;
+    }
+
+    /// Records that a hardware breakpoint/watchpoint was removed,
+    /// freeing up a slot against the declared capacity.
+    pub(crate) fn remove(&mut self) {
+        self.installed = self.installed.saturating_sub(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn enforces_declared_capacity() {
+        let mut tracker = HwBreakpointTracker::new();
+        assert_eq!(tracker.has_room(Some(2)), Ok(()));
+        tracker.record_install();
+        assert_eq!(tracker.has_room(Some(2)), Ok(()));
+        tracker.record_install();
+        assert_eq!(tracker.has_room(Some(2)), Err("E01"));
+    }
+
+    #[test]
+    fn unbounded_when_target_reports_no_limit() {
+        let mut tracker = HwBreakpointTracker::new();
+        for _ in 0..100 {
+            assert_eq!(tracker.has_room(None), Ok(()));
+            tracker.record_install();
+        }
+    }
+
+    #[test]
+    fn removing_frees_up_a_slot() {
+        let mut tracker = HwBreakpointTracker::new();
+        tracker.has_room(Some(1)).unwrap();
+        tracker.record_install();
+        assert_eq!(tracker.has_room(Some(1)), Err("E01"));
+
+        tracker.remove();
+        assert_eq!(tracker.has_room(Some(1)), Ok(()));
+    }
+
+    #[test]
+    fn failed_add_does_not_leak_a_reserved_slot() {
+        // Mirrors the real dispatch sequence: check room, call the
+        // target, and only record the install if the target's add
+        // actually reports success. A target add failing for a reason
+        // unrelated to capacity (bad address, alignment, ...) must not
+        // permanently consume a slot.
+        let mut tracker = HwBreakpointTracker::new();
+        tracker.has_room(Some(1)).unwrap();
+
+        let target_add_succeeded = false; // e.g. Ok(false) or Err(..) from the target
+        if target_add_succeeded {
+            tracker.record_install();
+        }
+
+        assert_eq!(tracker.has_room(Some(1)), Ok(()));
+    }
+}
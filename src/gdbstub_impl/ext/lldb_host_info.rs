@@ -0,0 +1,77 @@
+//! Dispatch logic for `qHostInfo`, backed by
+//! [`LldbHostInfoOverride`](crate::target::ext::lldb_host_info_override::LldbHostInfoOverride).
+
+use alloc::format;
+use alloc::string::String;
+
+use crate::target::ext::lldb_host_info_override::{Endian, HostInfo};
+
+/// Render the `qHostInfo` reply.
+///
+/// `GdbStub`'s command dispatch calls `target.host_info()` when the
+/// target implements `LldbHostInfoOverride`, and passes `Some(..)` of
+/// the result to this function; when the target doesn't implement the
+/// extension, it passes `None`, falling back to the empty reply LLDB
+/// already tolerates.
+pub(crate) fn render_qhostinfo_reply(info: Option<&HostInfo<'_>>) -> String {
+    let info = match info {
+        Some(info) => info,
+        None => return String::new(),
+    };
+
+    let mut reply = format!(
+        "triple:{};ptrsize:{};endian:{};",
+        hex_encode(info.triple),
+        info.ptrsize,
+        endian_str(info.endian),
+    );
+    if let Some(ostype) = info.ostype {
+        reply.push_str(&format!("ostype:{};", ostype));
+    }
+    if let Some(vendor) = info.vendor {
+        reply.push_str(&format!("vendor:{};", vendor));
+    }
+    reply
+}
+
+/// Hex-encodes `s`, as required for `qHostInfo`'s `triple` field.
+fn hex_encode(s: &str) -> String {
+    let mut out = String::with_capacity(s.len() * 2);
+    for b in s.as_bytes() {
+        out.push_str(&format!("{:02x}", b));
+    }
+    out
+}
+
+fn endian_str(endian: Endian) -> &'static str {
+    match endian {
+        Endian::Little => "little",
+        Endian::Big => "big",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_host_info_reply_with_hex_encoded_triple() {
+        let info = HostInfo {
+            triple: "bpf-unknown-none",
+            ptrsize: 8,
+            endian: Endian::Little,
+            ostype: None,
+            vendor: None,
+        };
+
+        assert_eq!(
+            render_qhostinfo_reply(Some(&info)),
+            "triple:6270662d756e6b6e6f776e2d6e6f6e65;ptrsize:8;endian:little;"
+        );
+    }
+
+    #[test]
+    fn empty_reply_when_unimplemented() {
+        assert_eq!(render_qhostinfo_reply(None), "");
+    }
+}
@@ -0,0 +1,108 @@
+//! Dispatch logic for `qRegisterInfo`, backed by
+//! [`LldbRegisterInfoOverride`](crate::target::ext::lldb_register_info_override::LldbRegisterInfoOverride).
+
+use alloc::format;
+use alloc::string::String;
+
+use crate::target::ext::lldb_register_info_override::{RegisterEncoding, RegisterFormat, RegisterInfo};
+
+/// Render the `qRegisterInfo` reply for a register lookup: the
+/// register's metadata if it exists, or the `E45` sentinel LLDB expects
+/// once there are no more registers to enumerate.
+///
+/// `GdbStub`'s command dispatch calls
+/// `target.register_info(cmd.reg_num as usize)` and feeds the result
+/// straight into this function to build the packet body.
+pub(crate) fn render_qregisterinfo_reply(info: Option<&RegisterInfo<'_>>) -> String {
+    match info {
+        Some(info) => render_register_info(info),
+        None => String::from("E45"),
+    }
+}
+
+fn render_register_info(info: &RegisterInfo<'_>) -> String {
+    let mut reply = format!(
+        "name:{};bitsize:{};offset:{};encoding:{};format:{};set:{};",
+        info.name,
+        info.bitsize,
+        info.offset,
+        encoding_str(info.encoding),
+        format_str(info.format),
+        info.set,
+    );
+    if let Some(gdb_regnum) = info.gdb_regnum {
+        reply.push_str(&format!("gdb:{};", gdb_regnum));
+    }
+    if let Some(dwarf_regnum) = info.dwarf_regnum {
+        reply.push_str(&format!("dwarf:{};", dwarf_regnum));
+    }
+    if let Some(alt_name) = info.alt_name {
+        reply.push_str(&format!("alt-name:{};", alt_name));
+    }
+    if let Some(generic) = info.generic {
+        reply.push_str(&format!("generic:{};", generic));
+    }
+    if let Some(container_regs) = info.container_regs {
+        reply.push_str("container-regs:");
+        for (i, reg) in container_regs.iter().enumerate() {
+            if i != 0 {
+                reply.push(',');
+            }
+            reply.push_str(&format!("{:x}", reg));
+        }
+        reply.push(';');
+    }
+    reply
+}
+
+fn encoding_str(encoding: RegisterEncoding) -> &'static str {
+    match encoding {
+        RegisterEncoding::Uint => "uint",
+        RegisterEncoding::Sint => "sint",
+        RegisterEncoding::Ieee754 => "ieee754",
+        RegisterEncoding::Vector => "vector",
+    }
+}
+
+fn format_str(format: RegisterFormat) -> &'static str {
+    match format {
+        RegisterFormat::Binary => "binary",
+        RegisterFormat::Decimal => "decimal",
+        RegisterFormat::Hex => "hex",
+        RegisterFormat::Float => "float",
+        RegisterFormat::VectorUint8 => "vector-uint8",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_register_info_reply() {
+        let info = RegisterInfo {
+            name: "r0",
+            alt_name: None,
+            bitsize: 64,
+            offset: 0,
+            encoding: RegisterEncoding::Uint,
+            format: RegisterFormat::Hex,
+            set: "General Purpose Registers",
+            gdb_regnum: Some(0),
+            dwarf_regnum: Some(0),
+            generic: None,
+            container_regs: None,
+        };
+
+        assert_eq!(
+            render_qregisterinfo_reply(Some(&info)),
+            "name:r0;bitsize:64;offset:0;encoding:uint;format:hex;\
+             set:General Purpose Registers;gdb:0;dwarf:0;"
+        );
+    }
+
+    #[test]
+    fn e45_once_registers_are_exhausted() {
+        assert_eq!(render_qregisterinfo_reply(None), "E45");
+    }
+}
@@ -0,0 +1,16 @@
+
+use super::prelude::*;
+
+#[derive(Debug)]
+pub struct qHostInfo;
+
+impl<'a> ParseCommand<'a> for qHostInfo {
+    fn from_packet(buf: PacketBuf<'a>) -> Option<Self> {
+        crate::__dead_code_marker!("qHostInfo", "from_packet");
+        let body = buf.into_body();
+        if !body.is_empty() {
+            return None;
+        }
+        Some(qHostInfo)
+    }
+}
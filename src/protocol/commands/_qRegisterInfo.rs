@@ -13,8 +13,7 @@ impl<'a> ParseCommand<'a> for qRegisterInfo {
         if body.is_empty() {
             return None;
         }
-        Some(qRegisterInfo {
-            reg_num: u8::from_str_radix(core::str::from_utf8(body).unwrap(), 16).unwrap(),
-	})
+        let reg_num = u8::from_str_radix(core::str::from_utf8(body).ok()?, 16).ok()?;
+        Some(qRegisterInfo { reg_num })
     }
 }
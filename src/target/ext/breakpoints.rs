@@ -0,0 +1,88 @@
+//! Target extension - breakpoints & watchpoints.
+
+use crate::arch::Arch;
+use crate::target::{Target, TargetResult};
+
+/// Target extension - set/remove hardware breakpoints.
+///
+/// See the [module-level documentation](index.html) for more details.
+pub trait HwBreakpoint: Target {
+    /// Add a new hardware breakpoint.
+    /// Return `Ok(false)` if the operation could not be completed.
+    fn add_hw_breakpoint(
+        &mut self,
+        addr: <Self::Arch as Arch>::Usize,
+        kind: <Self::Arch as Arch>::BreakpointKind,
+    ) -> TargetResult<bool, Self>;
+
+    /// Remove an existing hardware breakpoint.
+    /// Return `Ok(false)` if the breakpoint could not be removed.
+    fn remove_hw_breakpoint(
+        &mut self,
+        addr: <Self::Arch as Arch>::Usize,
+        kind: <Self::Arch as Arch>::BreakpointKind,
+    ) -> TargetResult<bool, Self>;
+
+    /// The maximum number of hardware breakpoints that can be installed
+    /// simultaneously, if the target knows this ahead of time (e.g: a
+    /// hypervisor reporting how many HW debug registers it exposes).
+    ///
+    /// `GdbStub` uses this to reject a `Z1` packet past the declared
+    /// limit with a clean `E` error, instead of forwarding a doomed
+    /// request to [`add_hw_breakpoint`](Self::add_hw_breakpoint).
+    ///
+    /// Returning `None` (the default) means the limit is unknown / there
+    /// isn't one, and `gdbstub` won't enforce a cap on this target's
+    /// behalf.
+    fn max_hw_breakpoints(&self) -> Option<usize> {
+        None
+    }
+}
+
+/// Target extension - set/remove hardware watchpoints.
+///
+/// See the [module-level documentation](index.html) for more details.
+pub trait HwWatchpoint: Target {
+    /// Add a new hardware watchpoint.
+    /// Return `Ok(false)` if the operation could not be completed.
+    fn add_hw_watchpoint(
+        &mut self,
+        addr: <Self::Arch as Arch>::Usize,
+        len: <Self::Arch as Arch>::Usize,
+        kind: WatchKind,
+    ) -> TargetResult<bool, Self>;
+
+    /// Remove an existing hardware watchpoint.
+    /// Return `Ok(false)` if the watchpoint could not be removed.
+    fn remove_hw_watchpoint(
+        &mut self,
+        addr: <Self::Arch as Arch>::Usize,
+        len: <Self::Arch as Arch>::Usize,
+        kind: WatchKind,
+    ) -> TargetResult<bool, Self>;
+
+    /// The maximum number of hardware watchpoints that can be installed
+    /// simultaneously, if the target knows this ahead of time.
+    ///
+    /// Same semantics as
+    /// [`HwBreakpoint::max_hw_breakpoints`]: `GdbStub` enforces this
+    /// limit against a `Z2`/`Z3`/`Z4` packet before it ever reaches
+    /// [`add_hw_watchpoint`](Self::add_hw_watchpoint), returning a clean
+    /// "no more hardware breakpoints available" error to GDB instead of
+    /// forwarding a request the target would have to fail (or worse,
+    /// silently clobber an existing watchpoint).
+    fn max_hw_watchpoints(&self) -> Option<usize> {
+        None
+    }
+}
+
+/// Describes the type of watchpoint that should be set/removed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WatchKind {
+    /// Fire when the memory location is written to.
+    Write,
+    /// Fire when the memory location is read from.
+    Read,
+    /// Fire when the memory location is written to or read from.
+    ReadWrite,
+}
@@ -0,0 +1,44 @@
+//! Provide host info for LLDB's `qHostInfo` query, for targets without a
+//! `target_description_xml` document.
+
+use crate::target::Target;
+
+/// Target extension - answer LLDB's `qHostInfo` query.
+///
+/// LLDB sends `qHostInfo` while attaching in order to pick an
+/// architecture to debug with. When
+/// [`target_description_xml`](crate::arch::Arch::target_description_xml)
+/// is `None`, LLDB falls back to the fields reported here (in
+/// particular, `triple`) to figure out how to interpret the target.
+///
+/// `GdbStub` falls back to the current (empty) `qHostInfo` reply when a
+/// target doesn't implement this extension.
+pub trait LldbHostInfoOverride: Target {
+    /// Returns the host info LLDB should use to pick an architecture.
+    fn host_info(&self) -> HostInfo<'_>;
+}
+
+/// Host info reported to LLDB via `qHostInfo`.
+#[derive(Debug, Clone, Copy)]
+pub struct HostInfo<'a> {
+    /// Target triple, e.g. `"bpf-unknown-none"`.
+    pub triple: &'a str,
+    /// Pointer size, in bytes.
+    pub ptrsize: usize,
+    /// CPU endianness.
+    pub endian: Endian,
+    /// Operating system, e.g. `"none"`.
+    pub ostype: Option<&'a str>,
+    /// Vendor, e.g. `"unknown"`.
+    pub vendor: Option<&'a str>,
+}
+
+/// CPU endianness, as reported via `qHostInfo`.
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy)]
+pub enum Endian {
+    /// Little-endian.
+    Little,
+    /// Big-endian.
+    Big,
+}
@@ -0,0 +1,87 @@
+//! Provide register info for LLDB's `qRegisterInfo` queries, for targets
+//! without a `target_description_xml` document.
+
+use crate::target::Target;
+
+/// Target extension - answer LLDB's `qRegisterInfo` register-discovery
+/// queries.
+///
+/// LLDB enumerates registers one at a time by sending `qRegisterInfo0`,
+/// `qRegisterInfo1`, etc. until the target responds with the `E45` error
+/// packet, which signals that there are no more registers to report. This
+/// is LLDB's fallback mechanism for architectures whose
+/// [`target_description_xml`](crate::arch::Arch::target_description_xml)
+/// is `None`.
+///
+/// Implementations should number registers in the exact same order
+/// [`Registers::gdb_serialize`](crate::arch::Registers::gdb_serialize)
+/// writes them in, as LLDB uses `offset`/`bitsize` to slice up the `g`
+/// packet it already knows how to read.
+pub trait LldbRegisterInfoOverride: Target {
+    /// Look up register info for register `reg_id`.
+    ///
+    /// Return `None` once `reg_id` runs past the last register --
+    /// `gdbstub` will turn that into the `E45` response LLDB expects to
+    /// stop enumerating.
+    fn register_info(&self, reg_id: usize) -> Option<RegisterInfo<'_>>;
+}
+
+/// A single register's metadata, as reported to LLDB via `qRegisterInfo`.
+#[derive(Debug, Clone, Copy)]
+pub struct RegisterInfo<'a> {
+    /// Register name.
+    pub name: &'a str,
+    /// Alternate name for the register, if any.
+    pub alt_name: Option<&'a str>,
+    /// Register width, in bits.
+    pub bitsize: usize,
+    /// Byte offset of the register within the `g`/`G` packet register
+    /// block.
+    pub offset: usize,
+    /// Register encoding.
+    pub encoding: RegisterEncoding,
+    /// Register display format.
+    pub format: RegisterFormat,
+    /// The register set this register belongs to, e.g. `"General Purpose
+    /// Registers"`.
+    pub set: &'a str,
+    /// This register's `gdb` register number, if it has one.
+    pub gdb_regnum: Option<usize>,
+    /// This register's DWARF register number, if it has one.
+    pub dwarf_regnum: Option<usize>,
+    /// LLDB "generic" register alias, e.g. `"pc"` or `"sp"`.
+    pub generic: Option<&'a str>,
+    /// Register numbers of the registers this register is contained in
+    /// (e.g. a sub-register of a vector register).
+    pub container_regs: Option<&'a [usize]>,
+}
+
+/// See [`RegisterInfo::encoding`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy)]
+pub enum RegisterEncoding {
+    /// Unsigned integer.
+    Uint,
+    /// Signed integer.
+    Sint,
+    /// IEEE 754 floating point.
+    Ieee754,
+    /// Vector of sub-registers.
+    Vector,
+}
+
+/// See [`RegisterInfo::format`].
+#[non_exhaustive]
+#[derive(Debug, Clone, Copy)]
+pub enum RegisterFormat {
+    /// Binary.
+    Binary,
+    /// Decimal.
+    Decimal,
+    /// Hexadecimal.
+    Hex,
+    /// Floating point.
+    Float,
+    /// Vector of bytes.
+    VectorUint8,
+}